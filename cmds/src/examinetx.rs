@@ -1,7 +1,33 @@
 use bitcoinsv::bitcoin::{FromHex, Tx};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
 use simple_logger::SimpleLogger;
-use one_sat_ordinals::OrdinalInscription;
+use one_sat_ordinals::{AddressKind, OrdinalInscription};
+
+/// The output format for the scanned inscriptions.
+#[derive(Copy, Clone, Debug, ValueEnum)]
+enum Format {
+    /// One debug-formatted inscription per line (the default).
+    Text,
+    /// A single JSON object, suitable for piping into other tooling.
+    Json,
+}
+
+/// The network to encode detected control addresses for.
+#[derive(Copy, Clone, Debug, ValueEnum)]
+enum CliNetwork {
+    Mainnet,
+    Testnet,
+}
+
+impl From<CliNetwork> for AddressKind {
+    fn from(n: CliNetwork) -> Self {
+        match n {
+            CliNetwork::Mainnet => AddressKind::Main,
+            CliNetwork::Testnet => AddressKind::NotMain,
+        }
+    }
+}
 
 /// Extract 1SatOrdinals data from a transaction.
 #[derive(Parser, Debug)]
@@ -12,8 +38,21 @@ struct Args {
     tx: String,
     #[clap(long, short, action)]
     trace: bool,
+    /// The output format.
+    #[clap(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// The network that the transaction was taken from, used to encode any detected control
+    /// address.
+    #[clap(long, value_enum, default_value_t = CliNetwork::Mainnet)]
+    network: CliNetwork,
 }
 
+/// The JSON output shape: the scanned transaction's hash plus its inscriptions.
+#[derive(Serialize)]
+struct JsonOutput<'a> {
+    tx_hash: String,
+    inscriptions: &'a [OrdinalInscription],
+}
 
 fn main() {
     let args: Args = Args::parse();
@@ -26,13 +65,27 @@ fn main() {
     match r {
         Err(err) => { println!("Error parsing tx, {}", err); }
         Ok(tx) => {
-            println!("tx hash: {}", tx.hash());
-            match OrdinalInscription::scan_tx(&tx) {
+            match OrdinalInscription::scan_tx(&tx, args.network.into()) {
                 Err(err) => { println!("Error scanning for inscriptions, {}", err); }
                 Ok(v) => {
-                    println!("found {} inscriptions", v.len());
-                    for t in v {
-                        println!("{:?}", t);
+                    match args.format {
+                        Format::Text => {
+                            println!("tx hash: {}", tx.hash());
+                            println!("found {} inscriptions", v.len());
+                            for t in v {
+                                println!("{:?}", t);
+                            }
+                        }
+                        Format::Json => {
+                            let output = JsonOutput {
+                                tx_hash: tx.hash().to_string(),
+                                inscriptions: &v,
+                            };
+                            match serde_json::to_string_pretty(&output) {
+                                Ok(s) => println!("{}", s),
+                                Err(err) => println!("Error serializing inscriptions, {}", err),
+                            }
+                        }
                     }
                 }
             }
@@ -0,0 +1,227 @@
+use serde::Deserialize;
+use crate::inscribe::OrdinalInscription;
+use crate::result::{OrdinalError, OrdinalResult};
+
+/// The content types that carry a BSV-20/BSV-21 token protocol payload.
+const BSV20_CONTENT_TYPE: &[u8] = b"application/bsv-20";
+const BSV21_CONTENT_TYPE: &[u8] = b"application/bsv-21";
+
+/// A parsed BSV-20 / BSV-21 token protocol operation, decoded from an [OrdinalInscription]'s
+/// body.
+///
+/// `mint`/`transfer` amounts are already expressed in the token's smallest unit, like ord's
+/// integer rune balances. A deploy's `max`/`lim` may instead be given as a decimal string, which
+/// is scaled up by the declared number of decimal places into that same smallest unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Bsv21Op {
+    /// Declares a token and its supply parameters.
+    Deploy {
+        /// The token's ticker (BSV-20) or id (BSV-21).
+        tick: String,
+        /// The number of decimal places amounts are denominated in.
+        dec: u8,
+        /// The total supply cap, in the token's smallest unit, if any.
+        max: Option<u128>,
+        /// The maximum amount that can be minted by a single mint operation, if any.
+        lim: Option<u128>,
+    },
+    /// Mints new supply of a token to the inscription's holder.
+    Mint {
+        /// The token's ticker (BSV-20) or id (BSV-21).
+        tick: String,
+        /// The amount minted, in the token's smallest unit.
+        amt: u128,
+    },
+    /// Transfers an amount of a token to the inscription's holder.
+    Transfer {
+        /// The token's ticker (BSV-20) or id (BSV-21).
+        tick: String,
+        /// The amount transferred, in the token's smallest unit.
+        amt: u128,
+    },
+}
+
+/// The raw JSON shape of a BSV-20/BSV-21 payload, before the declared amounts are resolved
+/// against the declared number of decimals.
+#[derive(Debug, Deserialize)]
+struct RawOp {
+    #[allow(dead_code)]
+    p: String,
+    op: String,
+    tick: Option<String>,
+    id: Option<String>,
+    amt: Option<String>,
+    max: Option<String>,
+    lim: Option<String>,
+    dec: Option<String>,
+}
+
+impl Bsv21Op {
+    /// Decode an [OrdinalInscription]'s body as a BSV-20/BSV-21 token protocol operation.
+    ///
+    /// Returns `Ok(None)` if the inscription's content type is not `application/bsv-20` or
+    /// `application/bsv-21`, so this can be used for detection purposes. Returns
+    /// [OrdinalError::BadProtocol] if the content type matches but the payload cannot be decoded.
+    pub fn from_inscription(inscription: &OrdinalInscription) -> OrdinalResult<Option<Bsv21Op>> {
+        let is_bsv21 = match inscription.creation_data.get(&1) {
+            Some(content_type) => {
+                content_type.as_ref() == BSV20_CONTENT_TYPE || content_type.as_ref() == BSV21_CONTENT_TYPE
+            }
+            None => false,
+        };
+        if !is_bsv21 {
+            return Ok(None);
+        }
+        let body = match inscription.metadata.get(&0) {
+            Some(body) => body,
+            None => return Ok(None),
+        };
+
+        let raw: RawOp = serde_json::from_slice(body)
+            .map_err(|e| OrdinalError::BadProtocol(format!("invalid bsv-20/bsv-21 json: {}", e)))?;
+        let tick = raw.tick.or(raw.id)
+            .ok_or_else(|| OrdinalError::BadProtocol("missing tick/id".to_string()))?;
+
+        match raw.op.as_str() {
+            "deploy" => {
+                let dec = match raw.dec {
+                    Some(d) => d.parse::<u8>()
+                        .map_err(|_| OrdinalError::BadProtocol(format!("invalid dec: {}", d)))?,
+                    None => 0,
+                };
+                let max = raw.max.map(|s| parse_amount(&s, dec)).transpose()?;
+                let lim = raw.lim.map(|s| parse_amount(&s, dec)).transpose()?;
+                Ok(Some(Bsv21Op::Deploy { tick, dec, max, lim }))
+            }
+            "mint" => {
+                let amt = raw.amt
+                    .ok_or_else(|| OrdinalError::BadProtocol("mint missing amt".to_string()))?;
+                Ok(Some(Bsv21Op::Mint { tick, amt: parse_plain_amount(&amt)? }))
+            }
+            "transfer" => {
+                let amt = raw.amt
+                    .ok_or_else(|| OrdinalError::BadProtocol("transfer missing amt".to_string()))?;
+                Ok(Some(Bsv21Op::Transfer { tick, amt: parse_plain_amount(&amt)? }))
+            }
+            other => Err(OrdinalError::BadProtocol(format!("unrecognized op: {}", other))),
+        }
+    }
+}
+
+/// Parse a plain integer amount, as used by `mint`/`transfer`'s `amt` field: already expressed
+/// in the token's smallest unit, with no decimal point.
+fn parse_plain_amount(s: &str) -> OrdinalResult<u128> {
+    s.parse().map_err(|_| OrdinalError::BadProtocol(format!("invalid amount: {}", s)))
+}
+
+/// Parse a decimal string amount (e.g. `"2864387"` or `"1.50"`) into the token's smallest unit,
+/// scaling it up by `dec` decimal places.
+fn parse_amount(s: &str, dec: u8) -> OrdinalResult<u128> {
+    let dec = dec as usize;
+    let scale = 10u128.checked_pow(dec as u32)
+        .ok_or_else(|| OrdinalError::BadProtocol(format!("dec too large: {}", dec)))?;
+    match s.split_once('.') {
+        None => {
+            let whole: u128 = s.parse()
+                .map_err(|_| OrdinalError::BadProtocol(format!("invalid amount: {}", s)))?;
+            whole.checked_mul(scale)
+                .ok_or_else(|| OrdinalError::BadProtocol(format!("amount overflow: {}", s)))
+        }
+        Some((whole, frac)) => {
+            if frac.len() > dec {
+                return Err(OrdinalError::BadProtocol(
+                    format!("amount {} has more decimal places than declared ({})", s, dec)));
+            }
+            let whole: u128 = whole.parse()
+                .map_err(|_| OrdinalError::BadProtocol(format!("invalid amount: {}", s)))?;
+            let frac_value: u128 = if frac.is_empty() {
+                0
+            } else {
+                let padded = format!("{:0<width$}", frac, width = dec);
+                padded.parse()
+                    .map_err(|_| OrdinalError::BadProtocol(format!("invalid amount: {}", s)))?
+            };
+            whole.checked_mul(scale).and_then(|w| w.checked_add(frac_value))
+                .ok_or_else(|| OrdinalError::BadProtocol(format!("amount overflow: {}", s)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use bitcoinsv::bitcoin::{Outpoint, TxHash};
+    use bytes::Bytes;
+
+    fn inscription_with_body(content_type: &'static [u8], body: &str) -> OrdinalInscription {
+        let mut creation_data = BTreeMap::new();
+        creation_data.insert(1, Bytes::from_static(content_type));
+        let mut metadata = BTreeMap::new();
+        metadata.insert(0, Bytes::from(body.as_bytes().to_vec()));
+        OrdinalInscription {
+            id: Outpoint { tx_hash: TxHash::from(&[0u8; 32][..]), index: 0 },
+            prev_id: None,
+            new_address: None,
+            must_be_creation: false,
+            creation_data,
+            metadata,
+            parent: None,
+            pointer: None,
+            delegate: None,
+            metaprotocol: None,
+            envelope_index: 0,
+            curse: None,
+        }
+    }
+
+    #[test]
+    fn ignores_non_bsv21_content_types() {
+        let i = inscription_with_body(b"text/plain", r#"{"p":"bsv-20","op":"mint","tick":"LOL","amt":"25"}"#);
+        assert_eq!(None, Bsv21Op::from_inscription(&i).unwrap());
+    }
+
+    #[test]
+    fn parses_deploy_with_decimal_scaling() {
+        let i = inscription_with_body(
+            b"application/bsv-20",
+            r#"{"p":"bsv-20","op":"deploy","tick":"LOL","max":"21000000","lim":"1000","dec":"2"}"#,
+        );
+        let op = Bsv21Op::from_inscription(&i).unwrap().unwrap();
+        assert_eq!(Bsv21Op::Deploy {
+            tick: "LOL".to_string(),
+            dec: 2,
+            max: Some(2_100_000_000),
+            lim: Some(100_000),
+        }, op);
+    }
+
+    #[test]
+    fn parses_mint_amount_as_plain_integer() {
+        let i = inscription_with_body(
+            b"application/bsv-20",
+            r#"{"p":"bsv-20","op":"mint","tick":"LOL","amt":"25"}"#,
+        );
+        let op = Bsv21Op::from_inscription(&i).unwrap().unwrap();
+        assert_eq!(Bsv21Op::Mint { tick: "LOL".to_string(), amt: 25 }, op);
+    }
+
+    #[test]
+    fn parses_transfer_amount_as_plain_integer() {
+        let i = inscription_with_body(
+            b"application/bsv-20",
+            r#"{"p":"bsv-20","op":"transfer","tick":"LOL","amt":"2864387"}"#,
+        );
+        let op = Bsv21Op::from_inscription(&i).unwrap().unwrap();
+        assert_eq!(Bsv21Op::Transfer { tick: "LOL".to_string(), amt: 2_864_387 }, op);
+    }
+
+    #[test]
+    fn deploy_rejects_a_dec_too_large_to_scale_by() {
+        let i = inscription_with_body(
+            b"application/bsv-21",
+            r#"{"p":"bsv-21","op":"deploy","id":"abc","max":"1","dec":"255"}"#,
+        );
+        assert!(matches!(Bsv21Op::from_inscription(&i), Err(OrdinalError::BadProtocol(_))));
+    }
+}
@@ -1,9 +1,42 @@
 use std::collections::BTreeMap;
-use bitcoinsv::bitcoin::{Address, FromHex, Operation, Outpoint, Tx, TxHash, TxOutput};
+use bitcoinsv::bitcoin::{Operation, Outpoint, Tx, TxHash, TxOutput};
 use bytes::Bytes;
 use log::trace;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
 use crate::result::OrdinalResult;
 
+/// A detected P2PKH control address, as a hash160 plus which address kind it should be rendered
+/// for.
+///
+/// `bitcoinsv::bitcoin::Address` has no public constructor that takes a raw hash160 directly (only
+/// ones built from a public/private key), so this crate keeps its own minimal representation
+/// rather than depend on `bitcoinsv` internals that aren't part of its public API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Address {
+    /// The 20-byte hash of the public key that controls this address.
+    pub hash160: [u8; 20],
+    /// Which network this address should be rendered for.
+    pub kind: AddressKind,
+}
+
+impl std::fmt::Display for Address {
+    /// Renders as the hex-encoded hash160, since base58check encoding requires network
+    /// parameters this crate does not otherwise depend on.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", bytes_to_hex(&Bytes::copy_from_slice(&self.hash160)))
+    }
+}
+
+/// The network an [Address] is rendered for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressKind {
+    /// Mainnet.
+    Main,
+    /// Any non-mainnet network (testnet, regtest, ...).
+    NotMain,
+}
+
 /// An OrdinalInscription is token data stored on-chain. It is used to define a token and assign its initial
 /// control [Address] or update a token and its control [Address].
 ///
@@ -35,6 +68,43 @@ pub struct OrdinalInscription {
     pub creation_data: BTreeMap<i64, Bytes>,
     /// Metadata. The Ordinals specification defines odd numbered fields as metadata fields.
     pub metadata: BTreeMap<i64, Bytes>,
+    /// The inscription that this inscription is a child of, identified by field 3.
+    pub parent: Option<Outpoint>,
+    /// The offset, in sats, within the containing output group that this inscription is
+    /// assigned to, identified by field 2. Defaults to `0` (the first sat) when absent.
+    pub pointer: Option<u64>,
+    /// Another inscription that this inscription's content should be inherited from,
+    /// identified by field 5.
+    pub delegate: Option<Outpoint>,
+    /// The metaprotocol that this inscription belongs to, identified by field 7.
+    pub metaprotocol: Option<String>,
+    /// The position of this inscription's envelope within the output's script, counting from
+    /// zero. An output's script can carry more than one stacked envelope.
+    pub envelope_index: u32,
+    /// Set when the envelope was malformed in some way that, following ord's cursed-inscription
+    /// rules, would still be recognized but assigned a negative/sentinel number by an indexer.
+    ///
+    /// Always `None` when scanned with [OrdinalInscription::scan_tx] or
+    /// [OrdinalInscription::scan_output]; only populated by
+    /// [OrdinalInscription::scan_output_with_diagnostics].
+    pub curse: Option<Curse>,
+}
+
+/// A reason an inscription envelope was flagged as cursed/malformed by
+/// [OrdinalInscription::scan_output_with_diagnostics].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Curse {
+    /// An even-numbered field was present that is not one of the recognized even fields (`0`
+    /// for the body, `2` for the pointer).
+    UnrecognizedEvenField,
+    /// The same field key appeared more than once in the envelope.
+    DuplicateField,
+    /// The envelope was never closed with an `OP_ENDIF`.
+    IncompleteEnvelope,
+    /// The body (field `0`) was followed by further fields instead of closing the envelope.
+    NotBodyLast,
+    /// A field key was pushed using something other than a direct data push.
+    NotPushdata,
 }
 
 impl OrdinalInscription {
@@ -54,142 +124,317 @@ impl OrdinalInscription {
     ///
     /// If an inscription is invalid then it is ignored, not included in the result set, and this
     /// does not result in an error being returned.
-    pub fn scan_tx(tx: &Tx) -> OrdinalResult<Vec<Self>> {
+    pub fn scan_tx(tx: &Tx, kind: AddressKind) -> OrdinalResult<Vec<Self>> {
         let mut result = Vec::new();
         let mut index = 0;
         for o in &tx.outputs {
             trace!("scanning output {} of tx {}", index, tx.hash());
-            match Self::scan_output(o, &tx.hash(), index) {
-                None => {},
-                Some(i) => {
-                    trace!("found inscription {:?}", i);
-                    result.push(i);
-                },
-            }
+            let found = Self::scan_output(o, &tx.hash(), index, kind);
+            trace!("found {} inscription(s)", found.len());
+            result.extend(found);
             index += 1;
         }
         Ok(result)
     }
 
-    /// Scan an output for an inscription.
+    /// Scan an output for inscriptions.
     ///
     /// For the purposes of this function, an inscription is valid if it follows the conventions for
     /// data definition and the output has a value of 1 satoshi.
-    pub fn scan_output(txo: &TxOutput, tx_id: &TxHash, index: u32) -> Option<Self> {
-        if txo.value != 1  {
-            None
-        } else {
-            match txo.script.decode() {
-                Ok((ops, trailing)) => {
-                    let mut creation_data = BTreeMap::new();
-                    let mut metadata = BTreeMap::new();
-                    let mut key = 0i64;
-                    enum State { Initial, OpFalseSeen, OpIfSeen, InEnvelope, GotKey, GotBody }
-                    let mut state = State::Initial;
-                    for op in ops {
-                        match state {
-                            State::Initial => {
-                                // looking for initial OP_FALSE
-                                if op.eq_alias(&Operation::OP_FALSE) {
-                                    state = State::OpFalseSeen;
-                                    trace!("found OP_FALSE");
+    ///
+    /// A single output's script can carry more than one envelope stacked back-to-back; each
+    /// completed envelope is collected and scanning continues from where it left off, so this
+    /// returns a `Vec` rather than stopping at the first one.
+    ///
+    /// `kind` is used to encode the control [Address], if one is detected, so it must match the
+    /// network that `txo` was taken from.
+    pub fn scan_output(txo: &TxOutput, tx_id: &TxHash, index: u32, kind: AddressKind) -> Vec<Self> {
+        Self::scan_output_impl(txo, tx_id, index, kind, false)
+    }
+
+    /// Scan an output for inscriptions, same as [OrdinalInscription::scan_output], but without
+    /// discarding malformed envelopes.
+    ///
+    /// Mirroring ord's cursed-inscription rules, an envelope that deviates from the happy path
+    /// (an unrecognized even field, a duplicated field key, a missing closing `OP_ENDIF`, a
+    /// non-pushdata key, or a body that isn't the last field) is still parsed and returned, but
+    /// carries [OrdinalInscription::curse] describing the first anomaly found. This lets an
+    /// indexer distinguish "no inscription" from "malformed inscription" and assign the
+    /// negative/sentinel numbering that ord uses for cursed inscriptions.
+    pub fn scan_output_with_diagnostics(txo: &TxOutput, tx_id: &TxHash, index: u32, kind: AddressKind) -> Vec<Self> {
+        Self::scan_output_impl(txo, tx_id, index, kind, true)
+    }
+
+    fn scan_output_impl(txo: &TxOutput, tx_id: &TxHash, index: u32, kind: AddressKind, diagnostics: bool) -> Vec<Self> {
+        let mut result = Vec::new();
+        if txo.value != 1 {
+            return result;
+        }
+        match txo.script.decode() {
+            Ok((ops, _trailing)) => {
+                let mut creation_data = BTreeMap::new();
+                let mut metadata = BTreeMap::new();
+                let mut parent = None;
+                let mut pointer = None;
+                let mut delegate = None;
+                let mut metaprotocol = None;
+                let mut curse: Option<Curse> = None;
+                let mut key = 0i64;
+                let mut envelope_index = 0u32;
+                enum State { Initial, OpFalseSeen, OpIfSeen, InEnvelope, GotKey, GotBody }
+                let mut state = State::Initial;
+                let mut op_index = 0usize;
+                while op_index < ops.len() {
+                    let op = &ops[op_index];
+                    match state {
+                        State::Initial => {
+                            // looking for initial OP_FALSE
+                            if op.eq_alias(&Operation::OP_FALSE) {
+                                state = State::OpFalseSeen;
+                                trace!("found OP_FALSE");
+                            }
+                        },
+                        State::OpFalseSeen => {
+                            // next op must be OP_IF
+                            if *op == Operation::OP_IF {
+                                state = State::OpIfSeen;
+                                trace!("found OP_IF");
+                            } else {
+                                state = State::Initial;
+                            }
+                        },
+                        State::OpIfSeen => {
+                            // next must be "ord" on stack
+                            match op.data_pushed() {
+                                None => { state = State::Initial; },
+                                Some(d) => {
+                                    trace!("found data push after OP_IF");
+                                    if d.len() != 3 {
+                                        state = State::Initial;
+                                    } else if d.slice(0..3) == "ord" {
+                                        // reset all collected data so far
+                                        creation_data = BTreeMap::new();
+                                        metadata = BTreeMap::new();
+                                        parent = None;
+                                        pointer = None;
+                                        delegate = None;
+                                        metaprotocol = None;
+                                        curse = None;
+                                        key = 0;
+                                        state = State::InEnvelope;
+                                        trace!("in 1satordinal envelope");
+                                    } else {
+                                        state = State::Initial;
+                                    }
                                 }
-                            },
-                            State::OpFalseSeen => {
-                                // next op must be OP_IF
-                                if op == Operation::OP_IF {
-                                    state = State::OpIfSeen;
-                                    trace!("found OP_IF");
+                            }
+                        },
+                        State::InEnvelope => {
+                            // in the envelope, next must be a key
+                            if op.is_data_push() {
+                                if let Some(v) = op.small_num_pushed() {
+                                    key = v;
+                                    state = State::GotKey;
+                                    trace!("got key {}", key);
                                 } else {
+                                    // its not valid, go back to beginning
                                     state = State::Initial;
                                 }
-                            },
-                            State::OpIfSeen => {
-                                // next must be "ord" on stack
-                                match op.data_pushed() {
-                                    None => { state = State::Initial; },
-                                    Some(d) => {
-                                        trace!("found data push after OP_IF");
-                                        if d.len() != 3 {
-                                            state = State::Initial;
-                                        } else if d.slice(0..3) == "ord" {
-                                            // reset all collected data so far
-                                            creation_data = BTreeMap::new();
-                                            metadata = BTreeMap::new();
-                                            key = 0;
-                                            state = State::InEnvelope;
-                                            trace!("in 1satordinal envelope");
-                                        } else {
-                                            state = State::Initial;
-                                        }
+                            } else if diagnostics {
+                                curse.get_or_insert(Curse::NotPushdata);
+                                state = State::Initial;
+                            } else {
+                                // its not valid, go back to beginning
+                                state = State::Initial;
+                            }
+                        },
+                        State::GotKey => {
+                            // got a key, next must be a value
+                            if let Some(d) = op.data_pushed() {
+                                trace!("got value, length {}", d.len());
+                                if diagnostics {
+                                    if creation_data.contains_key(&key) || metadata.contains_key(&key) {
+                                        curse.get_or_insert(Curse::DuplicateField);
                                     }
+                                    if key % 2 == 0 && key != 0 && key != 2 {
+                                        curse.get_or_insert(Curse::UnrecognizedEvenField);
+                                    }
+                                }
+                                match key {
+                                    2 => pointer = Some(Self::le_bytes_to_u64(&d)),
+                                    3 => parent = Self::parse_outpoint(&d),
+                                    5 => delegate = Self::parse_outpoint(&d),
+                                    7 => metaprotocol = String::from_utf8(d.to_vec()).ok(),
+                                    _ => {},
                                 }
-                            },
-                            State::InEnvelope => {
-                                // in the envelope, next must be a key
+                                if key % 2 == 0 {
+                                    metadata.insert(key, d);
+                                } else {
+                                    creation_data.insert(key, d);
+                                }
+                                if key == 0 {
+                                    state = State::GotBody;
+                                } else {
+                                    state = State::InEnvelope;
+                                }
+                            } else {
+                                // its not valid, go back to the beginning
+                                state = State::Initial;
+                            }
+                        },
+                        State::GotBody => {
+                            // we found the body, now close the envelope
+                            if *op == Operation::OP_ENDIF {
+                                let new_address = Self::detect_p2pkh_address(&ops[op_index + 1..], kind);
+                                result.push(Self {
+                                    id: Outpoint {
+                                        tx_hash: tx_id.clone(),
+                                        index,
+                                    },
+                                    prev_id: None,          // can't determine this from the output
+                                    new_address,
+                                    must_be_creation: false,
+                                    creation_data: std::mem::take(&mut creation_data),
+                                    metadata: std::mem::take(&mut metadata),
+                                    parent: parent.take(),
+                                    pointer: pointer.take(),
+                                    delegate: delegate.take(),
+                                    metaprotocol: metaprotocol.take(),
+                                    envelope_index,
+                                    curse: curse.take(),
+                                });
+                                envelope_index += 1;
+                                // keep scanning: another envelope may be stacked after this one
+                                state = State::Initial;
+                            } else if diagnostics {
+                                // the body wasn't the last field; keep collecting instead of discarding
+                                curse.get_or_insert(Curse::NotBodyLast);
                                 if op.is_data_push() {
                                     if let Some(v) = op.small_num_pushed() {
                                         key = v;
                                         state = State::GotKey;
-                                        trace!("got key {}", key);
                                     } else {
-                                        // its not valid, go back to beginning
                                         state = State::Initial;
                                     }
                                 } else {
-                                    // its not valid, go back to beginning
                                     state = State::Initial;
                                 }
-                            },
-                            State::GotKey => {
-                                // got a key, next must be a value
-                                if op.is_data_push() {
-                                    let d = op.data_pushed()?;
-                                    trace!("got value, length {}", d.len());
-                                    if key % 2 == 0 {
-                                        metadata.insert(key, d);
-                                    } else {
-                                        creation_data.insert(key, d);
-                                    }
-                                    if key == 0 {
-                                        state = State::GotBody;
-                                    } else {
-                                        state = State::InEnvelope;
-                                    }
-                                } else {
-                                    // its not valid, go back to the beginning
-                                    state = State::Initial;
-                                }
-                            },
-                            State::GotBody => {
-                                // we found the body, now close the envelope
-                                if op != Operation::OP_ENDIF {
-                                    // if its not followed by an OP_ENDIF then its invalid
-                                    state = State::Initial;
-                                } else {
-                                    return Some(Self {
-                                        id: Outpoint {
-                                            tx_hash: tx_id.clone(),
-                                            index,
-                                        },
-                                        prev_id: None,          // can't determine this from the output
-                                        new_address: None,      // todo: address detection
-                                        must_be_creation: false,
-                                        creation_data,
-                                        metadata,
-                                    })
-                                }
-                            },
-                        }
+                            } else {
+                                // if its not followed by an OP_ENDIF then its invalid
+                                state = State::Initial;
+                            }
+                        },
                     }
-                    None
-                },
-                Err(err) => {
-                    trace!("error decoding script, ignoring, error={:?}", err);
-                    None
-                }   // ignore scripts with errors
+                    op_index += 1;
+                }
+                if diagnostics && matches!(state, State::InEnvelope | State::GotKey | State::GotBody) {
+                    // the envelope was never closed with an OP_ENDIF
+                    result.push(Self {
+                        id: Outpoint {
+                            tx_hash: tx_id.clone(),
+                            index,
+                        },
+                        prev_id: None,
+                        new_address: None,
+                        must_be_creation: false,
+                        creation_data,
+                        metadata,
+                        parent,
+                        pointer,
+                        delegate,
+                        metaprotocol,
+                        envelope_index,
+                        curse: Some(curse.unwrap_or(Curse::IncompleteEnvelope)),
+                    });
+                }
             }
+            Err(err) => {
+                trace!("error decoding script, ignoring, error={:?}", err);
+            }   // ignore scripts with errors
         }
+        result
+    }
+
+    /// Recognize the canonical P2PKH control script that follows an envelope: `OP_DUP
+    /// OP_HASH160 <20 bytes> OP_EQUALVERIFY OP_CHECKSIG`.
+    ///
+    /// Following ord's practice of ignoring non-standard script pubkeys rather than erroring,
+    /// any other trailing script simply yields `None`.
+    fn detect_p2pkh_address(ops: &[Operation], kind: AddressKind) -> Option<Address> {
+        if ops.len() < 5 {
+            return None;
+        }
+        if ops[0] != Operation::OP_DUP || ops[1] != Operation::OP_HASH160 {
+            return None;
+        }
+        let hash = ops[2].data_pushed()?;
+        if hash.len() != 20 {
+            return None;
+        }
+        if ops[3] != Operation::OP_EQUALVERIFY || ops[4] != Operation::OP_CHECKSIG {
+            return None;
+        }
+        let mut pub_key_hash = [0u8; 20];
+        pub_key_hash.copy_from_slice(&hash);
+        Some(Address { hash160: pub_key_hash, kind })
+    }
+
+    /// Interpret up to 8 bytes as a little-endian unsigned integer, as used by the `pointer`
+    /// and inscription-id `index` field encodings. Trailing/absent bytes default to zero.
+    fn le_bytes_to_u64(d: &Bytes) -> u64 {
+        let mut buf = [0u8; 8];
+        let n = d.len().min(8);
+        buf[..n].copy_from_slice(&d[..n]);
+        u64::from_le_bytes(buf)
+    }
+
+    /// Parse a field value encoding an inscription id: a 32-byte txid followed by the output
+    /// index as trailing little-endian bytes (defaulting to `0` when absent).
+    fn parse_outpoint(d: &Bytes) -> Option<Outpoint> {
+        if d.len() < 32 {
+            return None;
+        }
+        let tx_hash = TxHash::from(&d[0..32]);
+        let index = Self::le_bytes_to_u64(&d.slice(32..d.len())) as u32;
+        Some(Outpoint { tx_hash, index })
+    }
+}
+
+/// Render an [Outpoint] as `<txid>:<index>` for machine-readable output.
+pub(crate) fn outpoint_to_string(o: &Outpoint) -> String {
+    format!("{}:{}", o.tx_hash, o.index)
+}
+
+/// Hex-encode a [Bytes] value for machine-readable output.
+pub(crate) fn bytes_to_hex(b: &Bytes) -> String {
+    b.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Hex-encode every value in a field map, keyed by the field number as a string (JSON object
+/// keys must be strings).
+fn field_map_to_hex(m: &BTreeMap<i64, Bytes>) -> BTreeMap<String, String> {
+    m.iter().map(|(k, v)| (k.to_string(), bytes_to_hex(v))).collect()
+}
+
+impl Serialize for OrdinalInscription {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("OrdinalInscription", 12)?;
+        state.serialize_field("id", &outpoint_to_string(&self.id))?;
+        state.serialize_field("prev_id", &self.prev_id.as_ref().map(outpoint_to_string))?;
+        state.serialize_field("new_address", &self.new_address.as_ref().map(|a| a.to_string()))?;
+        state.serialize_field("must_be_creation", &self.must_be_creation)?;
+        state.serialize_field("creation_data", &field_map_to_hex(&self.creation_data))?;
+        state.serialize_field("metadata", &field_map_to_hex(&self.metadata))?;
+        state.serialize_field("parent", &self.parent.as_ref().map(outpoint_to_string))?;
+        state.serialize_field("pointer", &self.pointer)?;
+        state.serialize_field("delegate", &self.delegate.as_ref().map(outpoint_to_string))?;
+        state.serialize_field("metaprotocol", &self.metaprotocol)?;
+        state.serialize_field("envelope_index", &self.envelope_index)?;
+        state.serialize_field("curse", &self.curse)?;
+        state.end()
     }
 }
 
@@ -212,7 +457,7 @@ mod tests {
         );
         for b in empty_tx {
             let t = Tx::from_binary_buf(&b).unwrap();
-            let r = OrdinalInscription::scan_tx(&t).unwrap();
+            let r = OrdinalInscription::scan_tx(&t, AddressKind::Main).unwrap();
             assert_eq!(0, r.len());
         }
     }
@@ -224,7 +469,33 @@ mod tests {
         //        Some(OrdinalInscription { id: Outpoint { tx_hash: 1fefad9e727d1e520c27372a12791c7d31ca9be933f46e92eb61da8e14ba2f6d, index: 1 }, prev_id: None, new_address: None, must_be_creation: false, creation_data: {1: b"application/bsv-20"}, metadata: {0: b"{\"p\":\"bsv-20\",\"op\":\"transfer\",\"amt\":\"2864387\",\"tick\":\"LOL\"}"} })
         //        Some(OrdinalInscription { id: Outpoint { tx_hash: 1fefad9e727d1e520c27372a12791c7d31ca9be933f46e92eb61da8e14ba2f6d, index: 0 }, prev_id: None, new_address: None, must_be_creation: false, creation_data: {1: b"application/bsv-20"}, metadata: {0: b"{\"p\":\"bsv-20\",\"op\":\"transfer\",\"amt\":\"25\",\"tick\":\"LOL\"}"} })
         let tx = Tx::from_hex("010000000288e9ce76cb52d0c845272d1688ea510d19cf59cb692a212ff2d5438f063cb441010000006b483045022100c3b7e1c067eca9741a8f74795c07743711b5f98070144b6d02d1875f7859652902202796066e482e5689101cb628bede30eb6898071d8900b459077ece2bf71e3ae2c121033ae28579dc1a189b1e7eef911ee9f18b914644b5dd9d00a4032a894ad8fb014fffffffff88e9ce76cb52d0c845272d1688ea510d19cf59cb692a212ff2d5438f063cb441030000006b483045022100c295812032c5b9778a6a093396cd29b0e427ea437c94c56834071b0a221a8d91022060acb00bad0a71b24d0879deb90ad2f894f9d43cd7021891f982f0ded85d2b85c1210288d08f20ccf5a908668160a8d0173f688f5d43fad9b7f8c33683b349c499154bffffffff0401000000000000006c0063036f726451126170706c69636174696f6e2f6273762d323000367b2270223a226273762d3230222c226f70223a227472616e73666572222c22616d74223a223235222c227469636b223a224c4f4c227d6876a914098ed6d96b6718444a39d9f27d9a3a6ab8200e9a88ac0100000000000000710063036f726451126170706c69636174696f6e2f6273762d3230003b7b2270223a226273762d3230222c226f70223a227472616e73666572222c22616d74223a2232383634333837222c227469636b223a224c4f4c227d6876a914ebccfc5b92b0345db0fcd3dba71ccd2464ce29b088acd0070000000000001976a9142bdf72063d9a16b7d642c0825577d957bd85c93b88ace0382b00000000001976a914099fde5ce081bd5c0b3b6ef84fcfcd7fae8a3f9b88ac00000000").unwrap();
-        let os = OrdinalInscription::scan_tx(&tx).unwrap();
+        let os = OrdinalInscription::scan_tx(&tx, AddressKind::Main).unwrap();
         assert_eq!(2, os.len());
+
+        // output 0: transfer amt "25", control script pays 098ed6d9...
+        let o0 = os.iter().find(|o| o.id.index == 0).unwrap();
+        assert_eq!(b"application/bsv-20".as_slice(), o0.creation_data[&1].as_ref());
+        assert_eq!(
+            br#"{"p":"bsv-20","op":"transfer","amt":"25","tick":"LOL"}"#.as_slice(),
+            o0.metadata[&0].as_ref(),
+        );
+        assert_eq!(hex!("098ed6d96b6718444a39d9f27d9a3a6ab8200e9a"), o0.new_address.as_ref().unwrap().hash160);
+        assert!(matches!(o0.new_address.as_ref().unwrap().kind, AddressKind::Main));
+        assert!(o0.prev_id.is_none());
+        assert!(!o0.must_be_creation);
+        assert!(o0.parent.is_none());
+        assert!(o0.pointer.is_none());
+        assert!(o0.delegate.is_none());
+        assert!(o0.metaprotocol.is_none());
+        assert_eq!(0, o0.envelope_index);
+        assert!(o0.curse.is_none());
+
+        // output 1: transfer amt "2864387", control script pays ebccfc5b...
+        let o1 = os.iter().find(|o| o.id.index == 1).unwrap();
+        assert_eq!(
+            br#"{"p":"bsv-20","op":"transfer","amt":"2864387","tick":"LOL"}"#.as_slice(),
+            o1.metadata[&0].as_ref(),
+        );
+        assert_eq!(hex!("ebccfc5b92b0345db0fcd3dba71ccd2464ce29b0"), o1.new_address.as_ref().unwrap().hash160);
     }
 }
\ No newline at end of file
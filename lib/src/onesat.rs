@@ -0,0 +1,264 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use bitcoinsv::bitcoin::Outpoint;
+use bytes::Bytes;
+use crate::inscribe::{outpoint_to_string, Address, OrdinalInscription};
+use crate::result::{OrdinalError, OrdinalResult};
+use crate::transfer::OrdinalTransfer;
+
+/// A single on-chain action that contributes to a 1SatOrdinal's lineage.
+///
+/// This is either the [OrdinalInscription] that creates or updates the token's data, or an
+/// [OrdinalTransfer] that only changes the controlling [Address].
+#[derive(Debug, Clone)]
+pub enum Action {
+    /// A creation or update inscription.
+    Inscription(OrdinalInscription),
+    /// A plain transfer of control.
+    Transfer(OrdinalTransfer),
+}
+
+impl Action {
+    /// The identifier of this action.
+    pub fn id(&self) -> Outpoint {
+        match self {
+            Action::Inscription(i) => i.id.clone(),
+            Action::Transfer(t) => t.id.clone(),
+        }
+    }
+
+    /// The identifier of the action that this action directly descends from, if known.
+    pub fn prev_id(&self) -> Option<Outpoint> {
+        match self {
+            Action::Inscription(i) => i.prev_id.clone(),
+            Action::Transfer(t) => Some(t.prev_id.clone()),
+        }
+    }
+
+    /// Whether this action is known to be the genesis of a token, as opposed to an update.
+    pub fn must_be_creation(&self) -> bool {
+        match self {
+            Action::Inscription(i) => i.must_be_creation,
+            Action::Transfer(_) => false,
+        }
+    }
+
+    /// The [Address] that this action assigns control to, if any.
+    pub fn new_address(&self) -> Option<Address> {
+        match self {
+            Action::Inscription(i) => i.new_address.clone(),
+            Action::Transfer(t) => Some(t.new_address.clone()),
+        }
+    }
+
+    /// The creation data carried by this action, if it is an inscription.
+    pub fn creation_data(&self) -> Option<&BTreeMap<i64, Bytes>> {
+        match self {
+            Action::Inscription(i) => Some(&i.creation_data),
+            Action::Transfer(_) => None,
+        }
+    }
+}
+
+/// A 1SatOrdinal token. This is an NFT token that is stored on-chain in transactions.
+///
+/// This struct collects 1SatOrdinal actions and presents the latest known state. It also provides
+/// access to the historical actions.
+///
+/// The possible actions that can be taken on an Ordinal are:
+///  * the initial [OrdinalInscription] which defines and creates the token and assigns it to an initial [Address],
+///  * additional [OrdinalInscription]s which can update the token data and transfer the token to a new [Address],
+///  * [OrdinalTransfer]s which transfer control to a new [Address].
+///
+/// Each 1SatOrdinal action has requirements to be considered valid and must be directly descended from a previous
+/// 1SatOrdinal action, with the exception of the initial action which creates the token.
+///
+/// todo: At the moment we only consider standard P2PKH control scripts.
+///
+/// See also [1satordinals.com](https://1satordinals.com/).
+#[derive(Debug, Clone)]
+pub struct Ordinal {
+    /// The actions that make up this token's lineage, in order from genesis to latest.
+    history: Vec<Action>,
+}
+
+impl Ordinal {
+    /// Partition a pool of actions, gathered from any number of transactions and in any order,
+    /// into the distinct token lineages they belong to.
+    ///
+    /// An action is the genesis of a lineage if it is flagged [Action::must_be_creation], or if
+    /// its `prev_id` does not resolve to another action in the pool. The latter case covers
+    /// "dangling" roots, where the token's earlier history is simply not present in `actions`; the
+    /// resulting [Ordinal] is still usable, just incomplete.
+    ///
+    /// A child action is linked to its parent when the child's `prev_id` matches the parent's
+    /// `id`; this is exactly the condition that the child's single-satoshi output was funded by
+    /// the parent's outpoint. If more than one action claims the same parent, the first one found
+    /// is linked and the rest are treated as separate dangling roots.
+    ///
+    /// An action flagged [Action::must_be_creation] is always treated as its own root, even if its
+    /// `prev_id` happens to resolve to another action in the pool; it is never linked into another
+    /// lineage as a child, so it cannot end up claimed by two roots at once.
+    ///
+    /// Returns [OrdinalError::BadArgument] if `actions` contains a cycle of `prev_id` links with no
+    /// root to traverse from (e.g. two non-creation actions whose `prev_id`s resolve to each
+    /// other); such a pool is malformed and every action in it would otherwise be silently dropped
+    /// instead of appearing in any returned [Ordinal].
+    pub fn from_actions(actions: Vec<Action>) -> OrdinalResult<Vec<Ordinal>> {
+        let by_id: HashMap<Outpoint, Action> = actions.into_iter().map(|a| (a.id(), a)).collect();
+
+        // index actions by the id of the action they claim to descend from. An action known to be
+        // a creation is never indexed as someone else's child, since it is always its own root.
+        let mut children: HashMap<Outpoint, Vec<Outpoint>> = HashMap::new();
+        for a in by_id.values() {
+            if a.must_be_creation() {
+                continue;
+            }
+            if let Some(prev) = a.prev_id() {
+                if by_id.contains_key(&prev) {
+                    children.entry(prev).or_default().push(a.id());
+                }
+            }
+        }
+
+        // Outpoint has no Ord impl, so sort by a stable, orderable key derived from it; this only
+        // needs to be deterministic, not meaningful.
+        let mut claimed: HashMap<Outpoint, bool> = HashMap::new();
+        for ids in children.values_mut() {
+            ids.sort_by_key(outpoint_to_string);
+            for (n, id) in ids.iter().enumerate() {
+                claimed.insert(id.clone(), n == 0);
+            }
+        }
+
+        let mut roots: Vec<Outpoint> = by_id
+            .values()
+            .filter(|a| {
+                a.must_be_creation()
+                    || a.prev_id().map_or(true, |p| !by_id.contains_key(&p))
+                    || !claimed.get(&a.id()).copied().unwrap_or(false)
+            })
+            .map(|a| a.id())
+            .collect();
+        roots.sort_by_key(outpoint_to_string);
+
+        let mut result = Vec::with_capacity(roots.len());
+        let mut reached: HashSet<Outpoint> = HashSet::new();
+        for root in roots {
+            let mut history = vec![by_id[&root].clone()];
+            reached.insert(root.clone());
+            let mut current = root;
+            while let Some(next) = children.get(&current).and_then(|ids| ids.first()) {
+                history.push(by_id[next].clone());
+                reached.insert(next.clone());
+                current = next.clone();
+            }
+            result.push(Ordinal { history });
+        }
+
+        // Every action not reached from a root is part of a cycle: a run of non-creation actions
+        // whose prev_id links chain back into each other with no dangling end to start from.
+        if reached.len() != by_id.len() {
+            return Err(OrdinalError::BadArgument(format!(
+                "{} action(s) form a cycle of prev_id links with no root to traverse from",
+                by_id.len() - reached.len()
+            )));
+        }
+        Ok(result)
+    }
+
+    /// The [Address] currently controlling the token, according to the latest known action.
+    pub fn latest_address(&self) -> Option<Address> {
+        self.history.iter().rev().find_map(|a| a.new_address())
+    }
+
+    /// The token data from the latest known inscription, if any.
+    pub fn latest_data(&self) -> Option<&BTreeMap<i64, Bytes>> {
+        self.history.iter().rev().find_map(|a| a.creation_data())
+    }
+
+    /// The full, ordered history of actions known for this token, from genesis to latest.
+    pub fn history(&self) -> &[Action] {
+        &self.history
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoinsv::bitcoin::TxHash;
+    use crate::inscribe::AddressKind;
+
+    fn outpoint(index: u32) -> Outpoint {
+        // distinct but deterministic tx_hash per index, so each outpoint is unique
+        let mut bytes = [0u8; 32];
+        bytes[0] = index as u8;
+        Outpoint { tx_hash: TxHash::from(&bytes[..]), index }
+    }
+
+    fn address(byte: u8) -> Address {
+        Address { hash160: [byte; 20], kind: AddressKind::Main }
+    }
+
+    fn inscription(id: u32, prev_id: Option<u32>, must_be_creation: bool, new_address: Option<Address>) -> Action {
+        Action::Inscription(OrdinalInscription {
+            id: outpoint(id),
+            prev_id: prev_id.map(outpoint),
+            new_address,
+            must_be_creation,
+            creation_data: BTreeMap::new(),
+            metadata: BTreeMap::new(),
+            parent: None,
+            pointer: None,
+            delegate: None,
+            metaprotocol: None,
+            envelope_index: 0,
+            curse: None,
+        })
+    }
+
+    fn transfer(id: u32, prev_id: u32, new_address: Address) -> Action {
+        Action::Transfer(OrdinalTransfer { id: outpoint(id), prev_id: outpoint(prev_id), new_address })
+    }
+
+    #[test]
+    fn chains_a_creation_and_a_transfer_into_one_lineage() {
+        let creation = inscription(0, None, true, Some(address(1)));
+        let moved = transfer(1, 0, address(2));
+        let ordinals = Ordinal::from_actions(vec![creation, moved]).unwrap();
+        assert_eq!(1, ordinals.len());
+        assert_eq!(2, ordinals[0].history().len());
+        assert_eq!([2u8; 20], ordinals[0].latest_address().unwrap().hash160);
+    }
+
+    #[test]
+    fn dangling_prev_id_is_still_its_own_root() {
+        let orphan = inscription(1, Some(0), false, Some(address(1)));
+        let ordinals = Ordinal::from_actions(vec![orphan]).unwrap();
+        assert_eq!(1, ordinals.len());
+        assert_eq!(1, ordinals[0].history().len());
+    }
+
+    #[test]
+    fn must_be_creation_with_resolvable_prev_id_is_not_linked_as_a_child() {
+        // `x` claims to descend from `y`, but is itself flagged must_be_creation; it must not be
+        // folded into y's lineage as well as forming its own.
+        let y = inscription(0, None, true, Some(address(1)));
+        let x = inscription(1, Some(0), true, Some(address(2)));
+        let ordinals = Ordinal::from_actions(vec![y, x]).unwrap();
+        assert_eq!(2, ordinals.len());
+        let total: usize = ordinals.iter().map(|o| o.history().len()).sum();
+        assert_eq!(2, total);
+        for o in &ordinals {
+            assert_eq!(1, o.history().len());
+        }
+    }
+
+    #[test]
+    fn a_cycle_of_prev_ids_is_an_error() {
+        // `a` claims to descend from `b` and `b` claims to descend from `a`; neither is a
+        // creation, so neither qualifies as a root and the pair would otherwise vanish silently.
+        let a = inscription(0, Some(1), false, Some(address(1)));
+        let b = inscription(1, Some(0), false, Some(address(2)));
+        assert!(Ordinal::from_actions(vec![a, b]).is_err());
+    }
+}
@@ -8,12 +8,15 @@ pub type OrdinalResult<T> = Result<T, OrdinalError>;
 pub enum OrdinalError {
     /// An argument provided is invalid
     BadArgument(String),
+    /// A token protocol payload (e.g. BSV-20/BSV-21) is malformed
+    BadProtocol(String),
 }
 
 impl std::fmt::Display for OrdinalError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             OrdinalError::BadArgument(s) => f.write_str(&format!("Bad argument: {}", s)),
+            OrdinalError::BadProtocol(s) => f.write_str(&format!("Bad protocol: {}", s)),
         }
     }
 }
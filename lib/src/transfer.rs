@@ -1,4 +1,7 @@
-use bitcoinsv::bitcoin::{Address, Outpoint};
+use bitcoinsv::bitcoin::Outpoint;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use crate::inscribe::{outpoint_to_string, Address};
 
 
 /// A transfer of control of an [Ordinal] from one [Address] to another.
@@ -13,3 +16,16 @@ pub struct OrdinalTransfer {
     /// The address to which the [Ordinal] has been assigned.
     pub new_address: Address,
 }
+
+impl Serialize for OrdinalTransfer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("OrdinalTransfer", 3)?;
+        state.serialize_field("id", &outpoint_to_string(&self.id))?;
+        state.serialize_field("prev_id", &outpoint_to_string(&self.prev_id))?;
+        state.serialize_field("new_address", &self.new_address.to_string())?;
+        state.end()
+    }
+}